@@ -1,11 +1,13 @@
 use async_trait::async_trait;
-pub use conservator_macro::{auto, sql, Creatable, Domain};
+pub use conservator_macro::{auto, sql, Creatable, Domain, Updatable};
 
 pub use sqlx::migrate;
 pub use sqlx::postgres::PgPoolOptions;
 pub use sqlx::FromRow;
 pub use sqlx::{Pool, Postgres};
 
+pub mod schema;
+
 pub type SingleNumberRow = (i32,);
 
 #[derive(FromRow)]
@@ -13,10 +15,20 @@ pub struct ExistsRow {
     pub exists: Option<bool>,
 }
 
+/// clauses appended to the `truncate table` statement of `Domain::truncate`, all disabled by default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TruncateOptions {
+    /// reset the sequences owned by the table's columns, `restart identity`
+    pub restart_identity: bool,
+    /// truncate the tables referencing this one by foreign keys as well, `cascade`
+    pub cascade: bool,
+}
+
 #[async_trait]
 pub trait Domain: Sized {
     const PK_FIELD_NAME: &'static str;
     const TABLE_NAME: &'static str;
+    const UPDATE_SQL: &'static str;
 
     type PrimaryKey;
 
@@ -38,6 +50,11 @@ pub trait Domain: Sized {
         executor: E,
     ) -> Result<Vec<Self>, ::sqlx::Error>;
 
+    async fn fetch_by_pks<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+        pks: &[Self::PrimaryKey],
+        executor: E,
+    ) -> Result<Vec<Self>, ::sqlx::Error>;
+
     async fn create<
         'e,
         'c: 'e,
@@ -62,6 +79,31 @@ pub trait Domain: Sized {
         entity: Self,
         executor: E,
     ) -> Result<(), ::sqlx::Error>;
+
+    async fn update_returning<
+        'e,
+        'c: 'e,
+        E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>,
+    >(
+        entity: Self,
+        executor: E,
+    ) -> Result<Self, ::sqlx::Error>;
+
+    async fn patch_by_pk<
+        'e,
+        'c: 'e,
+        E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>,
+        U: Updatable,
+    >(
+        pk: &Self::PrimaryKey,
+        data: U,
+        executor: E,
+    ) -> Result<(), ::sqlx::Error>;
+
+    async fn truncate<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+        options: TruncateOptions,
+        executor: E,
+    ) -> Result<(), ::sqlx::Error>;
 }
 
 pub trait Creatable: Send {
@@ -96,6 +138,22 @@ pub trait Creatable: Send {
     >;
 }
 
+pub trait Updatable: Send {
+    fn get_set_columns(&self) -> Vec<&'static str>;
+    fn build_for_query<'q>(
+        self,
+        e: ::sqlx::query::Query<
+            'q,
+            ::sqlx::Postgres,
+            <::sqlx::Postgres as ::sqlx::database::HasArguments<'q>>::Arguments,
+        >,
+    ) -> ::sqlx::query::Query<
+        'q,
+        ::sqlx::Postgres,
+        <::sqlx::Postgres as ::sqlx::database::HasArguments<'q>>::Arguments,
+    >;
+}
+
 #[cfg(test)]
 mod test {
     #[test]
@@ -0,0 +1,130 @@
+use sqlx::FromRow;
+
+const INTROSPECT_TABLE_SQL: &str = r#"
+select c.column_name::text as name,
+       format_type(a.atttypid, a.atttypmod) as data_type,
+       c.is_nullable = 'YES' as is_nullable,
+       c.column_default::text as "default",
+       col_description(t.oid, a.attnum) as comment,
+       obj_description(t.oid, 'pg_class') as table_comment
+from information_schema.columns c
+         join pg_catalog.pg_class t on t.relname = c.table_name
+         join pg_catalog.pg_namespace n on n.oid = t.relnamespace and n.nspname = c.table_schema
+         join pg_catalog.pg_attribute a on a.attrelid = t.oid and a.attname = c.column_name
+where c.table_schema = current_schema()
+  and c.table_name = $1
+order by c.ordinal_position
+"#;
+
+#[derive(Debug, Clone)]
+pub struct TableInfo {
+    pub name: String,
+    pub comment: Option<String>,
+    pub columns: Vec<ColumnInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnInfo {
+    pub name: String,
+    /// the type as written in DDL, e.g. `character varying(255)`, `text[]` or the name of an enum
+    pub data_type: String,
+    pub is_nullable: bool,
+    pub default: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(FromRow)]
+struct ColumnRow {
+    name: String,
+    data_type: String,
+    is_nullable: bool,
+    default: Option<String>,
+    comment: Option<String>,
+    table_comment: Option<String>,
+}
+
+/// read the columns of `table` in the current schema, in declaration order, from the catalogs
+///
+/// returns `sqlx::Error::RowNotFound` if the table does not exist
+pub async fn introspect_table<
+    'e,
+    'c: 'e,
+    E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>,
+>(
+    executor: E,
+    table: &str,
+) -> Result<TableInfo, ::sqlx::Error> {
+    let rows: Vec<ColumnRow> = sqlx::query_as(INTROSPECT_TABLE_SQL)
+        .bind(table)
+        .fetch_all(executor)
+        .await?;
+    to_table_info(table, rows)
+}
+
+fn to_table_info(table: &str, rows: Vec<ColumnRow>) -> Result<TableInfo, ::sqlx::Error> {
+    let comment = match rows.first() {
+        Some(row) => row.table_comment.clone(),
+        None => return Err(::sqlx::Error::RowNotFound),
+    };
+    let columns = rows
+        .into_iter()
+        .map(|row| ColumnInfo {
+            name: row.name,
+            data_type: row.data_type,
+            is_nullable: row.is_nullable,
+            default: row.default,
+            comment: row.comment,
+        })
+        .collect();
+    Ok(TableInfo {
+        name: table.to_string(),
+        comment,
+        columns,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{to_table_info, ColumnRow};
+
+    fn row(name: &str, data_type: &str) -> ColumnRow {
+        ColumnRow {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            is_nullable: false,
+            default: None,
+            comment: None,
+            table_comment: Some("registered users".to_string()),
+        }
+    }
+
+    #[test]
+    fn should_map_rows_into_table_info() {
+        let mut email = row("email", "character varying(255)");
+        email.is_nullable = true;
+        email.comment = Some("login email".to_string());
+        let rows = vec![row("id", "integer"), email, row("tags", "text[]")];
+
+        let info = to_table_info("users", rows).unwrap();
+
+        assert_eq!("users", info.name);
+        assert_eq!(Some("registered users".to_string()), info.comment);
+        assert_eq!(
+            vec!["id", "email", "tags"],
+            info.columns
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("character varying(255)", info.columns[1].data_type);
+        assert!(info.columns[1].is_nullable);
+        assert_eq!(Some("login email".to_string()), info.columns[1].comment);
+        assert_eq!("text[]", info.columns[2].data_type);
+    }
+
+    #[test]
+    fn should_return_row_not_found_for_missing_table() {
+        let err = to_table_info("missing", vec![]).unwrap_err();
+        assert!(matches!(err, sqlx::Error::RowNotFound));
+    }
+}
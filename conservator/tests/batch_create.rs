@@ -0,0 +1,80 @@
+use conservator::{Creatable, Domain, FromRow, PgPoolOptions};
+use sqlx::database::HasArguments;
+use sqlx::query::{Query, QueryAs};
+use sqlx::Postgres;
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    pub email: String,
+}
+
+pub struct NewUser {
+    columns: &'static str,
+}
+
+impl Creatable for NewUser {
+    fn get_columns(&self) -> &str {
+        self.columns
+    }
+
+    fn get_insert_sql(&self) -> &str {
+        "($1)"
+    }
+
+    fn get_batch_insert_sql(&self, idx: usize) -> String {
+        format!("(${})", idx + 1)
+    }
+
+    fn build_for_query_as<'q, O>(
+        self,
+        e: QueryAs<'q, Postgres, O, <Postgres as HasArguments<'q>>::Arguments>,
+    ) -> QueryAs<'q, Postgres, O, <Postgres as HasArguments<'q>>::Arguments> {
+        e
+    }
+
+    fn build_for_query<'q>(
+        self,
+        e: Query<'q, Postgres, <Postgres as HasArguments<'q>>::Arguments>,
+    ) -> Query<'q, Postgres, <Postgres as HasArguments<'q>>::Arguments> {
+        e
+    }
+}
+
+#[tokio::test]
+async fn should_reject_inconsistent_columns_before_touching_the_database() {
+    let pool = PgPoolOptions::new()
+        .connect_lazy("postgres://localhost/conservator")
+        .unwrap();
+    let data = vec![
+        NewUser {
+            columns: "(\"username\")",
+        },
+        NewUser {
+            columns: "(\"email\")",
+        },
+    ];
+
+    let err = User::batch_create(data, &pool).await.unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::Configuration(_)));
+    assert_eq!(
+        "error with configuration: inconsistent columns in batch create, expected (\"username\")",
+        err.to_string()
+    );
+}
+
+#[tokio::test]
+async fn should_reject_creatable_without_columns() {
+    let pool = PgPoolOptions::new()
+        .connect_lazy("postgres://localhost/conservator")
+        .unwrap();
+    let data = vec![NewUser { columns: "" }, NewUser { columns: "" }];
+
+    let err = User::batch_create(data, &pool).await.unwrap_err();
+
+    assert!(matches!(err, sqlx::Error::Configuration(_)));
+}
@@ -0,0 +1,18 @@
+use conservator::{Creatable, Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+}
+
+#[derive(Creatable)]
+#[creatable(for = "User")]
+pub struct NewUser {
+    pub username: String,
+    pub nickname: String,
+}
+
+fn main() {}
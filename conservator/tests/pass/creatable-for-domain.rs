@@ -0,0 +1,19 @@
+use conservator::{Creatable, Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    pub email: String,
+}
+
+#[derive(Creatable)]
+#[creatable(for = "User")]
+pub struct NewUser {
+    pub username: String,
+    pub email: String,
+}
+
+fn main() {}
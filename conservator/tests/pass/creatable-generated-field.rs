@@ -0,0 +1,20 @@
+use conservator::Creatable;
+
+#[derive(Creatable)]
+pub struct NewUser {
+    #[creatable(generated)]
+    pub id: i32,
+    pub username: String,
+    pub email: String,
+}
+
+fn main() {
+    let data = NewUser {
+        id: 1,
+        username: "kilerd".to_string(),
+        email: "kilerd@example.com".to_string(),
+    };
+    assert_eq!("(\"username\",\"email\")", data.get_columns());
+    assert_eq!("($1,$2)", data.get_insert_sql());
+    assert_eq!("($3,$4)", data.get_batch_insert_sql(1));
+}
@@ -0,0 +1,10 @@
+use conservator::Creatable;
+
+#[derive(Creatable)]
+pub struct NewCounter {}
+
+fn main() {
+    let data = NewCounter {};
+    assert_eq!("", data.get_columns());
+    assert_eq!("", data.get_insert_sql());
+}
@@ -0,0 +1,12 @@
+use conservator::{Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+pub struct UserProfile {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub nickname: String,
+}
+
+fn main() {
+    assert_eq!("user_profiles", UserProfile::TABLE_NAME);
+}
@@ -0,0 +1,18 @@
+use conservator::{Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    #[domain(immutable)]
+    pub created_at: i64,
+}
+
+fn main() {
+    assert_eq!(
+        "UPDATE users SET \"username\" = $1 WHERE \"id\" = $2",
+        User::UPDATE_SQL
+    );
+}
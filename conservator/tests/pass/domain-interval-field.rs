@@ -0,0 +1,14 @@
+use conservator::{Domain, FromRow};
+use sqlx::postgres::types::PgInterval;
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "sessions")]
+pub struct Session {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub ttl: PgInterval,
+}
+
+fn main() {
+    assert_eq!("sessions", Session::TABLE_NAME);
+}
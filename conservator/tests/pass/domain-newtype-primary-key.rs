@@ -0,0 +1,17 @@
+use conservator::{Domain, FromRow};
+
+#[derive(Debug, sqlx::Type)]
+#[sqlx(transparent)]
+pub struct UserId(i32);
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: UserId,
+    pub username: String,
+}
+
+fn main() {
+    assert_eq!("id", User::PK_FIELD_NAME);
+}
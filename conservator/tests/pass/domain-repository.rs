@@ -0,0 +1,22 @@
+use conservator::{Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users", repository)]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+}
+
+pub struct UserService;
+
+impl UserRepository for UserService {}
+
+pub async fn find_user(
+    service: &impl UserRepository,
+    pool: &conservator::Pool<conservator::Postgres>,
+) -> Result<Option<User>, sqlx::Error> {
+    service.find_by_pk(&1, pool).await
+}
+
+fn main() {}
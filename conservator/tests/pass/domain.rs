@@ -0,0 +1,15 @@
+use conservator::{Domain, FromRow};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    pub email: String,
+}
+
+fn main() {
+    assert_eq!("users", User::TABLE_NAME);
+    assert_eq!("id", User::PK_FIELD_NAME);
+}
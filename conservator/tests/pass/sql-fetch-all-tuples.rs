@@ -0,0 +1,12 @@
+use conservator::sql;
+
+pub struct UserService;
+
+impl UserService {
+    #[sql(fetch_all)]
+    pub async fn user_names(active: bool) -> Vec<(i32, String)> {
+        "select id, username from users where active = :active"
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use conservator::{Domain, FromRow, Updatable};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    pub nickname: Option<String>,
+}
+
+#[derive(Updatable)]
+#[updatable(for = "User")]
+pub struct UserPatch {
+    pub username: Option<String>,
+    pub nickname: Option<Option<String>>,
+}
+
+fn main() {
+    let patch = UserPatch {
+        username: None,
+        nickname: Some(None),
+    };
+    assert_eq!(vec!["nickname"], patch.get_set_columns());
+}
@@ -0,0 +1,39 @@
+use conservator::{Domain, FromRow, PgPoolOptions, Updatable};
+
+#[derive(Debug, Domain, FromRow)]
+#[domain(table = "users")]
+pub struct User {
+    #[domain(primary_key)]
+    pub id: i32,
+    pub username: String,
+    pub email: String,
+}
+
+#[derive(Updatable)]
+#[updatable(for = "User")]
+pub struct UserPatch {
+    pub username: Option<String>,
+    pub email: Option<String>,
+}
+
+#[test]
+fn should_only_set_some_fields() {
+    let patch = UserPatch {
+        username: None,
+        email: Some("a@b.c".to_string()),
+    };
+    assert_eq!(vec!["email"], patch.get_set_columns());
+}
+
+#[tokio::test]
+async fn should_skip_patch_without_fields() {
+    let pool = PgPoolOptions::new()
+        .connect_lazy("postgres://localhost/conservator")
+        .unwrap();
+    let patch = UserPatch {
+        username: None,
+        email: None,
+    };
+
+    User::patch_by_pk(&1, patch, &pool).await.unwrap();
+}
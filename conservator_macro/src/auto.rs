@@ -76,7 +76,14 @@ enum Factor {
     Equals,
     Gt,
     Lt,
+    /// binds the whole list as one array parameter via `= ANY($n)`, so the statement
+    /// shape stays the same whatever the list length is
     In,
+    /// the negation of `In` via `<> ALL($n)`, an empty list matches every row
+    NotIn,
+    Like,
+    NotLike,
+    Ilike,
 }
 
 impl Factor {
@@ -86,9 +93,11 @@ impl Factor {
             Factor::Equals => "=".to_string(),
             Factor::Gt => ">".to_string(),
             Factor::Lt => "<".to_string(),
-            Factor::In => {
-                todo!()
-            }
+            Factor::In => "= ANY".to_string(),
+            Factor::NotIn => "<> ALL".to_string(),
+            Factor::Like => "like".to_string(),
+            Factor::NotLike => "not like".to_string(),
+            Factor::Ilike => "ilike".to_string(),
         }
     }
 }
@@ -102,12 +111,20 @@ struct FieldFactor {
 
 impl FieldFactor {
     fn as_sql_where(&self) -> String {
-        format!(
-            "{} {} ${}",
-            self.field,
-            self.factor.as_sql_factor(),
-            self.index + 1
-        )
+        match self.factor {
+            Factor::In | Factor::NotIn => format!(
+                "{} {}(${})",
+                self.field,
+                self.factor.as_sql_factor(),
+                self.index + 1
+            ),
+            _ => format!(
+                "{} {} ${}",
+                self.field,
+                self.factor.as_sql_factor(),
+                self.index + 1
+            ),
+        }
     }
 }
 
@@ -142,7 +159,7 @@ pub(crate) fn handler(input: proc_macro2::TokenStream) -> proc_macro2::TokenStre
 }
 
 pub(crate) fn handler_magic_function(
-    header: &Box<Type>,
+    header: &Type,
     method: &ImplItemMethod,
 ) -> proc_macro2::TokenStream {
     let vis = &method.vis;
@@ -156,12 +173,12 @@ pub(crate) fn handler_magic_function(
 
     let action_name = action_caps.name("action").map_or("", |m| m.as_str());
     let action: Action = Action::from_str(action_name).unwrap();
-    let field_naming_convention =
-        regex::Regex::new("__(?P<field>[^__]+)__(?P<factor>is|equals|gt|lt|in)?")
-            .expect("invalid regex");
+    let field_naming_convention = regex::Regex::new(
+        "__(?P<field>[^__]+)__(?P<factor>is|equals|gt|lt|in|not_in|like|not_like|ilike)?",
+    )
+    .expect("invalid regex");
     let field_factors = field_naming_convention
         .captures_iter(&ident_name)
-        .into_iter()
         .enumerate()
         .map(|(idx, cap)| {
             let field_name = cap.name("field").map_or("", |m| m.as_str());
@@ -201,3 +218,118 @@ pub(crate) fn handler_magic_function(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::auto::handler;
+
+    #[test]
+    fn should_bind_in_factor_as_array() {
+        use quote::quote;
+        let input = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__id__in(id: Vec<i32>, executor: E) -> Result<Vec<UserEntity>, ::sqlx::Error> {}
+            }
+        };
+
+        let expected = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__id__in<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                    id: Vec<i32>,
+                    executor: E
+                ) -> Result<Vec<UserEntity>, ::sqlx::Error> {
+                    let table_name = UserService::table_name();
+                    let sql = format!("select * from {} where id = ANY($1)", table_name);
+                    sqlx::query_as(&sql)
+                        .bind(id)
+                        .fetch_all(executor)
+                        .await
+                }
+            }
+        };
+        assert_eq!(expected.to_string(), handler(input).to_string());
+    }
+
+    #[test]
+    fn should_match_case_insensitively_with_ilike_factor() {
+        use quote::quote;
+        let input = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__username__ilike(username: String, executor: E) -> Result<Vec<UserEntity>, ::sqlx::Error> {}
+            }
+        };
+
+        let expected = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__username__ilike<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                    username: String,
+                    executor: E
+                ) -> Result<Vec<UserEntity>, ::sqlx::Error> {
+                    let table_name = UserService::table_name();
+                    let sql = format!("select * from {} where username ilike $1", table_name);
+                    sqlx::query_as(&sql)
+                        .bind(username)
+                        .fetch_all(executor)
+                        .await
+                }
+            }
+        };
+        assert_eq!(expected.to_string(), handler(input).to_string());
+    }
+
+    #[test]
+    fn should_bind_not_in_factor_as_array() {
+        use quote::quote;
+        let input = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__id__not_in(id: Vec<i32>, executor: E) -> Result<Vec<UserEntity>, ::sqlx::Error> {}
+            }
+        };
+
+        let expected = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__id__not_in<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                    id: Vec<i32>,
+                    executor: E
+                ) -> Result<Vec<UserEntity>, ::sqlx::Error> {
+                    let table_name = UserService::table_name();
+                    let sql = format!("select * from {} where id <> ALL($1)", table_name);
+                    sqlx::query_as(&sql)
+                        .bind(id)
+                        .fetch_all(executor)
+                        .await
+                }
+            }
+        };
+        assert_eq!(expected.to_string(), handler(input).to_string());
+    }
+
+    #[test]
+    fn should_combine_not_like_with_other_factors() {
+        use quote::quote;
+        let input = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__email__not_like__age__gt(email: String, age: i32, executor: E) -> Result<Vec<UserEntity>, ::sqlx::Error> {}
+            }
+        };
+
+        let expected = quote! {
+            impl UserService {
+                pub async fn fetch_all_by__email__not_like__age__gt<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                    email: String,
+                    age: i32,
+                    executor: E
+                ) -> Result<Vec<UserEntity>, ::sqlx::Error> {
+                    let table_name = UserService::table_name();
+                    let sql = format!("select * from {} where email not like $1 and age > $2", table_name);
+                    sqlx::query_as(&sql)
+                        .bind(email)
+                        .bind(age)
+                        .fetch_all(executor)
+                        .await
+                }
+            }
+        };
+        assert_eq!(expected.to_string(), handler(input).to_string());
+    }
+}
@@ -1,13 +1,23 @@
-use darling::FromDeriveInput;
+use darling::{FromDeriveInput, FromField};
 use itertools::Itertools;
 use proc_macro_error::abort;
 use quote::quote;
 use syn::{parse2, Data, DeriveInput};
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(crud))]
+#[darling(attributes(creatable))]
 struct CreatableOpts {
     ident: syn::Ident,
+    #[darling(default, rename = "for")]
+    domain: Option<syn::Path>,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(creatable))]
+struct CreatableFieldOpt {
+    ident: Option<syn::Ident>,
+    #[darling(default)]
+    generated: bool,
 }
 
 pub(crate) fn handle_creatable(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
@@ -17,7 +27,13 @@ pub(crate) fn handle_creatable(input: proc_macro2::TokenStream) -> proc_macro2::
     let ident = creatable_opts.ident;
 
     if let Data::Struct(ref body) = x1.data {
-        let fields = body.fields.iter().map(|it| &it.ident).collect::<Vec<_>>();
+        let fields = body
+            .fields
+            .iter()
+            .map(|it| CreatableFieldOpt::from_field(it).unwrap())
+            .filter(|it| !it.generated)
+            .map(|it| it.ident)
+            .collect::<Vec<_>>();
 
         let field_list = fields
             .iter()
@@ -37,8 +53,12 @@ pub(crate) fn handle_creatable(input: proc_macro2::TokenStream) -> proc_macro2::
             .map(|it| format!("${}", it + 1))
             .join(",");
         
-        let columns = format!("({})", field_list);
-        let insert_sql = format!("({})", param_list);
+        // an empty column list means an all-default row, which `Domain::create` turns into `DEFAULT VALUES`
+        let (columns, insert_sql) = if fields.is_empty() {
+            (String::new(), String::new())
+        } else {
+            (format!("({})", field_list), format!("({})", param_list))
+        };
         
         let fields_len = fields.len();
 
@@ -49,7 +69,22 @@ pub(crate) fn handle_creatable(input: proc_macro2::TokenStream) -> proc_macro2::
             quote! { .bind(self. #it)}
         });
 
+        // accessing every inserted field on the domain makes a misspelled or unknown column a compile error
+        // field privacy applies as usual, so the domain's fields must be visible from the creatable's module
+        let domain_check = creatable_opts.domain.map(|domain| {
+            quote! {
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn check_columns_exist(it: #domain) {
+                        #(let _ = &it. #fields;)*
+                    }
+                };
+            }
+        });
+
         quote! {
+            #domain_check
+
             impl ::conservator::Creatable for #ident {
 
                 fn get_columns(&self) -> &str {
@@ -1,7 +1,7 @@
 use darling::{FromDeriveInput, FromField};
 use itertools::Itertools;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::{parse2, DeriveInput};
 
@@ -9,7 +9,13 @@ use syn::{parse2, DeriveInput};
 #[darling(attributes(domain))]
 struct DomainOpts {
     ident: syn::Ident,
-    table: String,
+    vis: syn::Visibility,
+    #[darling(default)]
+    table: Option<String>,
+    #[darling(default)]
+    insert_into: Option<String>,
+    #[darling(default)]
+    repository: bool,
     data: darling::ast::Data<darling::util::Ignored, DomainFieldOpt>,
 }
 
@@ -20,6 +26,35 @@ struct DomainFieldOpt {
     ty: syn::Type,
     #[darling(default)]
     primary_key: Option<bool>,
+    #[darling(default)]
+    immutable: bool,
+}
+
+fn default_table_name(ident: &syn::Ident) -> String {
+    let chars = ident.to_string().chars().collect_vec();
+    let mut name = String::new();
+    for (idx, ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && idx > 0 {
+            let prev = chars[idx - 1];
+            let next_is_lower = chars.get(idx + 1).is_some_and(|it| it.is_lowercase());
+            if !prev.is_uppercase() || next_is_lower {
+                name.push('_');
+            }
+        }
+        name.extend(ch.to_lowercase());
+    }
+
+    let before_last = name.chars().rev().nth(1);
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|it| name.ends_with(it))
+    {
+        format!("{}es", name)
+    } else if name.ends_with('y') && before_last.is_some_and(|it| !"aeiou".contains(it)) {
+        format!("{}ies", &name[..name.len() - 1])
+    } else {
+        format!("{}s", name)
+    }
 }
 
 fn find_by_id(table_name: &str, primary_field_name: &str) -> String {
@@ -31,6 +66,12 @@ fn find_by_id(table_name: &str, primary_field_name: &str) -> String {
 fn fetch_all(table_name: &str) -> String {
     format!("select * from {}", table_name)
 }
+fn fetch_by_pks(table_name: &str, primary_field_name: &str) -> String {
+    format!(
+        "select * from {} where \"{}\" = ANY($1) order by array_position($1, \"{}\")",
+        table_name, primary_field_name, primary_field_name
+    )
+}
 
 fn delete_by_pk(table_name: &str, primary_field_name: &str) -> String {
     format!(
@@ -38,11 +79,14 @@ fn delete_by_pk(table_name: &str, primary_field_name: &str) -> String {
         table_name, primary_field_name
     )
 }
+fn truncate(table_name: &str) -> String {
+    format!("truncate table {}", table_name)
+}
 fn update_sql(table_name: &str, primary_field_name: &str, non_pk_fields: &[syn::Ident]) -> String {
     let set_part = non_pk_fields
         .iter()
         .enumerate()
-        .map(|(idx, field)| format!("\"{}\" = ${}", field.to_string(), idx + 1))
+        .map(|(idx, field)| format!("\"{}\" = ${}", field, idx + 1))
         .join(", ");
     format!(
         "UPDATE {} SET {} WHERE \"{}\" = ${}",
@@ -53,6 +97,52 @@ fn update_sql(table_name: &str, primary_field_name: &str, non_pk_fields: &[syn::
     )
 }
 
+fn repository(
+    vis: &syn::Visibility,
+    ident: &syn::Ident,
+    pk_field_type: &syn::Type,
+) -> proc_macro2::TokenStream {
+    let repository_ident = format_ident!("{}Repository", ident);
+    quote! {
+        #[::async_trait::async_trait]
+        #vis trait #repository_ident: Sync {
+            async fn find_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database=::sqlx::Postgres>>(&self, pk: &#pk_field_type, executor: E) -> Result<Option<#ident>, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::find_by_pk(pk, executor).await
+            }
+            async fn fetch_one_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database=::sqlx::Postgres>>(&self, pk: &#pk_field_type, executor: E) -> Result<#ident, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::fetch_one_by_pk(pk, executor).await
+            }
+            async fn fetch_all<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database=::sqlx::Postgres>>(&self, executor: E) -> Result<Vec<#ident>, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::fetch_all(executor).await
+            }
+            async fn fetch_by_pks<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database=::sqlx::Postgres>>(&self, pks: &[#pk_field_type], executor: E) -> Result<Vec<#ident>, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::fetch_by_pks(pks, executor).await
+            }
+            async fn create<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, C: ::conservator::Creatable>(&self, data: C, executor: E) -> Result<#ident, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::create(data, executor).await
+            }
+            async fn batch_create<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, C: ::conservator::Creatable>(&self, data: Vec<C>, executor: E) -> Result<(), ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::batch_create(data, executor).await
+            }
+            async fn delete_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(&self, pk: &#pk_field_type, executor: E) -> Result<(), ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::delete_by_pk(pk, executor).await
+            }
+            async fn update<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(&self, entity: #ident, executor: E) -> Result<(), ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::update(entity, executor).await
+            }
+            async fn update_returning<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(&self, entity: #ident, executor: E) -> Result<#ident, ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::update_returning(entity, executor).await
+            }
+            async fn patch_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, U: ::conservator::Updatable>(&self, pk: &#pk_field_type, data: U, executor: E) -> Result<(), ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::patch_by_pk(pk, data, executor).await
+            }
+            async fn truncate<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(&self, options: ::conservator::TruncateOptions, executor: E) -> Result<(), ::sqlx::Error> {
+                <#ident as ::conservator::Domain>::truncate(options, executor).await
+            }
+        }
+    }
+}
+
 pub(crate) fn handler(
     input: proc_macro2::TokenStream,
 ) -> Result<proc_macro2::TokenStream, (Span, &'static str)> {
@@ -64,8 +154,15 @@ pub(crate) fn handler(
         .fields
         .iter()
         .filter(|field| field.primary_key.is_none())
+        .filter(|field| !field.immutable)
         .filter_map(|field| field.ident.clone())
         .collect_vec();
+    if non_pk_field_names.is_empty() && fields.fields.iter().any(|field| field.immutable) {
+        return Err((
+            x1.span(),
+            "every non primary key field is immutable, nothing left to update",
+        ));
+    }
 
     let mut pk_count = fields
         .fields
@@ -89,13 +186,25 @@ pub(crate) fn handler(
     let pk_field_name = pk_field_ident.clone().to_string();
     let pk_field_type = pk_field.ty;
 
-    let table_name = &crud_opts.table;
+    let table_name = crud_opts
+        .table
+        .unwrap_or_else(|| default_table_name(&crud_opts.ident));
+    // every write goes to `insert_into`, a view can never be truncated and a join view can't be updated either
+    let write_table_name = crud_opts.insert_into.unwrap_or_else(|| table_name.clone());
     let ident = crud_opts.ident;
+    let repository = if crud_opts.repository {
+        repository(&crud_opts.vis, &ident, &pk_field_type)
+    } else {
+        quote! {}
+    };
 
-    let find_by_id_sql = find_by_id(&crud_opts.table, &pk_field_name);
-    let fetch_all_sql = fetch_all(&crud_opts.table);
-    let delete_by_pk = delete_by_pk(&crud_opts.table, &pk_field_name);
-    let update_sql = update_sql(&crud_opts.table, &pk_field_name, &non_pk_field_names);
+    let find_by_id_sql = find_by_id(&table_name, &pk_field_name);
+    let fetch_all_sql = fetch_all(&table_name);
+    let fetch_by_pks_sql = fetch_by_pks(&table_name, &pk_field_name);
+    let delete_by_pk = delete_by_pk(&write_table_name, &pk_field_name);
+    let update_sql = update_sql(&write_table_name, &pk_field_name, &non_pk_field_names);
+    let update_returning_sql = format!("{} returning *", update_sql);
+    let truncate_sql = truncate(&write_table_name);
 
     let ret = quote! {
     
@@ -103,6 +212,7 @@ pub(crate) fn handler(
             impl ::conservator::Domain for #ident {
                 const PK_FIELD_NAME: &'static str = #pk_field_name;
                 const TABLE_NAME: &'static str = #table_name;
+                const UPDATE_SQL: &'static str = #update_sql;
     
                 type PrimaryKey = #pk_field_type;
     
@@ -125,10 +235,20 @@ pub(crate) fn handler(
                     .fetch_all(executor)
                     .await
                 }
+                async fn fetch_by_pks<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database=::sqlx::Postgres>>(pks: &[Self::PrimaryKey], executor: E) -> Result<Vec<Self>, ::sqlx::Error> {
+                    sqlx::query_as(#fetch_by_pks_sql)
+                    .bind(pks)
+                    .fetch_all(executor)
+                    .await
+                }
                 async fn create<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, C: ::conservator::Creatable>(
                     data: C, executor: E
                 ) -> Result<Self, ::sqlx::Error> {
-                    let sql = format!("INSERT INTO {} {} VALUES {} returning *", #table_name, data.get_columns(), data.get_insert_sql());
+                    let sql = if data.get_columns().is_empty() {
+                        format!("INSERT INTO {} DEFAULT VALUES returning *", #write_table_name)
+                    } else {
+                        format!("INSERT INTO {} {} VALUES {} returning *", #write_table_name, data.get_columns(), data.get_insert_sql())
+                    };
                     let mut ex = sqlx::query_as(&sql);
                     data.build_for_query_as(ex)
                         .fetch_one(executor)
@@ -142,6 +262,12 @@ pub(crate) fn handler(
                         return Ok(());
                     }
                     let columns = data[0].get_columns();
+                    if data.iter().any(|item| item.get_columns() != columns) {
+                        return Err(::sqlx::Error::Configuration(format!("inconsistent columns in batch create, expected {}", columns).into()));
+                    }
+                    if columns.is_empty() {
+                        return Err(::sqlx::Error::Configuration("batch create needs at least one column, use create for a Creatable without fields".into()));
+                    }
                     let mut insert_sql = String::new();
                     for (i, item) in data.iter().enumerate() {
                         if i > 0 {
@@ -149,7 +275,7 @@ pub(crate) fn handler(
                         }
                         insert_sql.push_str(item.get_batch_insert_sql(i).as_str());
                     }
-                    let sql = format!("INSERT INTO {} {} VALUES {}", #table_name, columns, insert_sql);
+                    let sql = format!("INSERT INTO {} {} VALUES {}", #write_table_name, columns, insert_sql);
                     let mut ex = sqlx::query(&sql);
                     for item in data {
                         ex = item.build_for_query(ex);
@@ -165,15 +291,54 @@ pub(crate) fn handler(
                     Ok(())
                 }
                 async fn update<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(entity:Self, executor: E) ->Result<(), ::sqlx::Error> {
-                    sqlx::query(#update_sql)
+                    sqlx::query(Self::UPDATE_SQL)
+                        #(.bind(entity. #non_pk_field_names))*
+                        .bind(entity. #pk_field_ident)
+                        .execute(executor)
+                        .await?;
+                    Ok(())
+                }
+                async fn update_returning<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(entity:Self, executor: E) ->Result<Self, ::sqlx::Error> {
+                    sqlx::query_as(#update_returning_sql)
                         #(.bind(entity. #non_pk_field_names))*
                         .bind(entity. #pk_field_ident)
+                        .fetch_one(executor)
+                        .await
+                }
+                async fn patch_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, U: ::conservator::Updatable>(pk: &Self::PrimaryKey, data: U, executor: E) ->Result<(), ::sqlx::Error> {
+                    let columns = data.get_set_columns();
+                    if columns.is_empty() {
+                        return Ok(());
+                    }
+                    let set_part = columns
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, column)| format!("\"{}\" = ${}", column, idx + 1))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let sql = format!("UPDATE {} SET {} WHERE \"{}\" = ${}", #write_table_name, set_part, #pk_field_name, columns.len() + 1);
+                    data.build_for_query(sqlx::query(&sql))
+                        .bind(pk)
+                        .execute(executor)
+                        .await?;
+                    Ok(())
+                }
+                async fn truncate<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(options: ::conservator::TruncateOptions, executor: E) ->Result<(), ::sqlx::Error> {
+                    let mut sql = String::from(#truncate_sql);
+                    if options.restart_identity {
+                        sql.push_str(" restart identity");
+                    }
+                    if options.cascade {
+                        sql.push_str(" cascade");
+                    }
+                    sqlx::query(&sql)
                         .execute(executor)
                         .await?;
                     Ok(())
                 }
             }
-    
+
+            #repository
         };
         Ok(ret)
 }
@@ -206,6 +371,7 @@ mod test {
             impl ::conservator::Domain for UserEntity {
                 const PK_FIELD_NAME: &'static str = "id";
                 const TABLE_NAME: &'static str = "users";
+                const UPDATE_SQL: &'static str = "UPDATE users SET \"username\" = $1, \"email\" = $2, \"password\" = $3, \"role\" = $4, \"create_at\" = $5, \"last_login_at\" = $6 WHERE \"id\" = $7";
                 type PrimaryKey = Uuid;
                 async fn find_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
                     pk: &Self::PrimaryKey,
@@ -235,6 +401,15 @@ mod test {
                         .fetch_all(executor)
                         .await
                 }
+                async fn fetch_by_pks<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                    pks: &[Self::PrimaryKey],
+                    executor: E
+                ) -> Result<Vec<Self>, ::sqlx::Error> {
+                    sqlx::query_as("select * from users where \"id\" = ANY($1) order by array_position($1, \"id\")")
+                        .bind(pks)
+                        .fetch_all(executor)
+                        .await
+                }
                 async fn create<
                     'e,
                     'c: 'e,
@@ -244,28 +419,46 @@ mod test {
                     data: C,
                     executor: E
                 ) -> Result<Self, ::sqlx::Error> {
-                    let sql = format!(
-                        "INSERT INTO {} {} VALUES {} returning *",
-                        "users",
-                        data.get_columns(),
-                        data.get_insert_sql()
-                    );
+                    let sql = if data.get_columns().is_empty() {
+                        format!("INSERT INTO {} DEFAULT VALUES returning *", "users")
+                    } else {
+                        format!(
+                            "INSERT INTO {} {} VALUES {} returning *",
+                            "users",
+                            data.get_columns(),
+                            data.get_insert_sql()
+                        )
+                    };
                     let mut ex = sqlx::query_as(&sql);
-                    data.build(ex).fetch_one(executor).await
+                    data.build_for_query_as(ex)
+                        .fetch_one(executor)
+                        .await
                 }
                 async fn batch_create<'data, 'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, C: ::conservator::Creatable>(
-                    data: &'data [C],
+                    data: Vec<C>,
                     executor: E,
                 ) -> Result<(), ::sqlx::Error> {
                     if data.is_empty() {
                         return Ok(());
                     }
                     let columns = data[0].get_columns();
-                    let insert_sql = data.iter().map(|it| it.get_insert_sql()).join(",");
+                    if data.iter().any(|item| item.get_columns() != columns) {
+                        return Err(::sqlx::Error::Configuration(format!("inconsistent columns in batch create, expected {}", columns).into()));
+                    }
+                    if columns.is_empty() {
+                        return Err(::sqlx::Error::Configuration("batch create needs at least one column, use create for a Creatable without fields".into()));
+                    }
+                    let mut insert_sql = String::new();
+                    for (i, item) in data.iter().enumerate() {
+                        if i > 0 {
+                            insert_sql.push_str(",");
+                        }
+                        insert_sql.push_str(item.get_batch_insert_sql(i).as_str());
+                    }
                     let sql = format!("INSERT INTO {} {} VALUES {}", "users", columns, insert_sql);
                     let mut ex = sqlx::query(&sql);
                     for item in data {
-                        ex = item.build(ex);
+                        ex = item.build_for_query(ex);
                     }
                     ex.execute(executor).await?;
                     Ok(())
@@ -280,7 +473,7 @@ mod test {
                 }
 
                 async fn update<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(entity:Self, executor: E) ->Result<(), ::sqlx::Error> {
-                    sqlx::query("UPDATE users SET \"username\" = $1, \"email\" = $2, \"password\" = $3, \"role\" = $4, \"create_at\" = $5, \"last_login_at\" = $6 WHERE \"id\" = $7")
+                    sqlx::query(Self::UPDATE_SQL)
                         .bind(entity.username)
                         .bind(entity.email)
                         .bind(entity.password)
@@ -292,10 +485,159 @@ mod test {
                         .await?;
                     Ok(())
                 }
+
+                async fn update_returning<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(entity:Self, executor: E) ->Result<Self, ::sqlx::Error> {
+                    sqlx::query_as("UPDATE users SET \"username\" = $1, \"email\" = $2, \"password\" = $3, \"role\" = $4, \"create_at\" = $5, \"last_login_at\" = $6 WHERE \"id\" = $7 returning *")
+                        .bind(entity.username)
+                        .bind(entity.email)
+                        .bind(entity.password)
+                        .bind(entity.role)
+                        .bind(entity.create_at)
+                        .bind(entity.last_login_at)
+                        .bind(entity.id)
+                        .fetch_one(executor)
+                        .await
+                }
+
+                async fn patch_by_pk<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>, U: ::conservator::Updatable>(pk: &Self::PrimaryKey, data: U, executor: E) ->Result<(), ::sqlx::Error> {
+                    let columns = data.get_set_columns();
+                    if columns.is_empty() {
+                        return Ok(());
+                    }
+                    let set_part = columns
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, column)| format!("\"{}\" = ${}", column, idx + 1))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    let sql = format!("UPDATE {} SET {} WHERE \"{}\" = ${}", "users", set_part, "id", columns.len() + 1);
+                    data.build_for_query(sqlx::query(&sql))
+                        .bind(pk)
+                        .execute(executor)
+                        .await?;
+                    Ok(())
+                }
+
+                async fn truncate<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(options: ::conservator::TruncateOptions, executor: E) ->Result<(), ::sqlx::Error> {
+                    let mut sql = String::from("truncate table users");
+                    if options.restart_identity {
+                        sql.push_str(" restart identity");
+                    }
+                    if options.cascade {
+                        sql.push_str(" cascade");
+                    }
+                    sqlx::query(&sql)
+                        .execute(executor)
+                        .await?;
+                    Ok(())
+                }
             }
         };
 
         let stream = handler(input).unwrap();
         assert_eq!(expected_output.to_string(), stream.to_string());
     }
+
+    #[test]
+    fn should_render_with_primary_key_declared_last() {
+        let input = quote! {
+            #[derive(Debug, Domain, FromRow)]
+            #[domain(table = "users")]
+            pub struct UserEntity {
+                pub username: String,
+                pub email: String,
+                #[domain(primary_key)]
+                pub id: i32,
+            }
+        };
+        let stream = handler(input).unwrap().to_string();
+
+        assert!(stream.contains(&quote! { const PK_FIELD_NAME: &'static str = "id"; }.to_string()));
+        assert!(stream.contains(&quote! { type PrimaryKey = i32; }.to_string()));
+        assert!(stream.contains(
+            &quote! { const UPDATE_SQL: &'static str = "UPDATE users SET \"username\" = $1, \"email\" = $2 WHERE \"id\" = $3"; }
+                .to_string()
+        ));
+        let expected_update = quote! {
+            sqlx::query(Self::UPDATE_SQL)
+                .bind(entity.username)
+                .bind(entity.email)
+                .bind(entity.id)
+        };
+        assert!(stream.contains(&expected_update.to_string()));
+    }
+
+    #[test]
+    fn should_insert_into_separate_table() {
+        let input = quote! {
+            #[derive(Debug, Domain, FromRow)]
+            #[domain(table = "v_users", insert_into = "users")]
+            pub struct UserEntity {
+                #[domain(primary_key)]
+                pub id: i32,
+                pub username: String,
+            }
+        };
+        let stream = handler(input).unwrap().to_string();
+
+        assert!(stream.contains(&quote! { sqlx::query_as("select * from v_users") }.to_string()));
+        assert!(stream.contains(
+            &quote! { format!("INSERT INTO {} DEFAULT VALUES returning *", "users") }.to_string()
+        ));
+        assert!(stream.contains(
+            &quote! { format!("INSERT INTO {} {} VALUES {}", "users", columns, insert_sql) }
+                .to_string()
+        ));
+        assert!(stream.contains(
+            &quote! { const UPDATE_SQL: &'static str = "UPDATE users SET \"username\" = $1 WHERE \"id\" = $2"; }
+                .to_string()
+        ));
+        assert!(stream.contains(
+            &quote! { sqlx::query_as("UPDATE users SET \"username\" = $1 WHERE \"id\" = $2 returning *") }
+                .to_string()
+        ));
+        assert!(stream
+            .contains(&quote! { sqlx::query("delete from users where \"id\" = $1") }.to_string()));
+        assert!(stream.contains(&quote! { String::from("truncate table users") }.to_string()));
+        assert!(stream.contains(
+            &quote! { format!("UPDATE {} SET {} WHERE \"{}\" = ${}", "users", set_part, "id", columns.len() + 1) }
+                .to_string()
+        ));
+        assert!(!stream.contains("UPDATE v_users"));
+    }
+
+    #[test]
+    fn should_reject_domain_without_updatable_fields() {
+        let input = quote! {
+            #[derive(Debug, Domain, FromRow)]
+            pub struct UserEntity {
+                #[domain(primary_key)]
+                pub id: i32,
+                #[domain(immutable)]
+                pub created_at: i64,
+            }
+        };
+        assert!(handler(input).is_err());
+    }
+
+    #[test]
+    fn should_default_table_name_to_plural_snake_case() {
+        use quote::format_ident;
+
+        use crate::domain::default_table_name;
+
+        assert_eq!("users", default_table_name(&format_ident!("User")));
+        assert_eq!(
+            "user_profiles",
+            default_table_name(&format_ident!("UserProfile"))
+        );
+        assert_eq!(
+            "http_requests",
+            default_table_name(&format_ident!("HTTPRequest"))
+        );
+        assert_eq!("categories", default_table_name(&format_ident!("Category")));
+        assert_eq!("keys", default_table_name(&format_ident!("Key")));
+        assert_eq!("addresses", default_table_name(&format_ident!("Address")));
+        assert_eq!("branches", default_table_name(&format_ident!("Branch")));
+    }
 }
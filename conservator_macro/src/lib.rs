@@ -6,6 +6,7 @@ mod auto;
 mod creatable;
 mod domain;
 mod sql;
+mod updatable;
 
 #[proc_macro_derive(Domain, attributes(domain))]
 #[proc_macro_error]
@@ -17,19 +18,24 @@ pub fn derive_domain_fn(input: TokenStream) -> TokenStream {
     }
 }
 
-#[proc_macro_derive(Creatable)]
+#[proc_macro_derive(Creatable, attributes(creatable))]
 #[proc_macro_error]
 pub fn derive_creatable_fn(input: TokenStream) -> TokenStream {
     let stream2 = proc_macro2::TokenStream::from(input);
-    let stream1 = proc_macro::TokenStream::from(creatable::handle_creatable(stream2));
-    stream1
+    proc_macro::TokenStream::from(creatable::handle_creatable(stream2))
+}
+
+#[proc_macro_derive(Updatable, attributes(updatable))]
+#[proc_macro_error]
+pub fn derive_updatable_fn(input: TokenStream) -> TokenStream {
+    let stream2 = proc_macro2::TokenStream::from(input);
+    proc_macro::TokenStream::from(updatable::handle_updatable(stream2))
 }
 
 #[proc_macro_attribute]
 pub fn auto(_args: TokenStream, input: TokenStream) -> TokenStream {
     let stream2 = proc_macro2::TokenStream::from(input);
-    let stream1 = proc_macro::TokenStream::from(auto::handler(stream2));
-    stream1
+    proc_macro::TokenStream::from(auto::handler(stream2))
 }
 
 #[proc_macro_attribute]
@@ -38,6 +38,7 @@ enum Action {
     Find,
     FetchAll,
     Execute,
+    ExecuteCount,
 }
 
 impl Action {
@@ -52,9 +53,12 @@ impl Action {
             .filter(|&field| !field.eq("executor"))
             .map(|field| format_ident!("{}", field))
             .collect_vec();
+        // `query_as!` only maps into named structs, so tuples fall back to the unchecked `query_as`
+        let checked = cfg!(debug_assertions)
+            && !matches!(parse2::<Type>(fetch_model.clone()), Ok(Type::Tuple(_)));
         match self {
             Action::Fetch => {
-                if cfg!(debug_assertions) {
+                if checked {
                     quote! {
                         ::sqlx::query_as!(#fetch_model, #sql, #(#fields,)*)
                             .fetch_one(executor)
@@ -71,7 +75,7 @@ impl Action {
             }
             Action::Exists => {
                 let exist_wrapper_sql = format!("select exists({})", sql);
-                if cfg!(debug_assertions) {
+                if checked {
                     quote! {
                         Ok(::sqlx::query_as!(#fetch_model, #exist_wrapper_sql, #(#fields,)*)
                             .fetch_one(executor)
@@ -87,7 +91,7 @@ impl Action {
                 }
             }
             Action::Find => {
-                if cfg!(debug_assertions) {
+                if checked {
                     quote! {
                         ::sqlx::query_as!(#fetch_model, #sql, #(#fields,)*)
                             .fetch_optional(executor)
@@ -103,7 +107,7 @@ impl Action {
                 }
             }
             Action::FetchAll => {
-                if cfg!(debug_assertions) {
+                if checked {
                     quote! {
                         ::sqlx::query_as!(#fetch_model, #sql, #(#fields,)*)
                             .fetch_all(executor)
@@ -119,7 +123,7 @@ impl Action {
                 }
             }
             Action::Execute => {
-                if cfg!(debug_assertions) {
+                if checked {
                     quote! {
                         ::sqlx::query_as!(#fetch_model, #sql, #(#fields,)*)
                             .execute(executor)
@@ -136,6 +140,22 @@ impl Action {
                     }
                 }
             }
+            Action::ExecuteCount => {
+                if checked {
+                    quote! {
+                        Ok(::sqlx::query_as!(#fetch_model, #sql, #(#fields,)*)
+                            .execute(executor)
+                            .await?.rows_affected())
+                    }
+                } else {
+                    quote! {
+                        Ok(::sqlx::query(#sql)
+                        #(.bind(#fields))*
+                        .execute(executor)
+                        .await?.rows_affected())
+                    }
+                }
+            }
         }
     }
 
@@ -145,9 +165,7 @@ impl Action {
     ) -> Result<(proc_macro2::TokenStream, proc_macro2::TokenStream), (Span, &'static str)> {
         let span = ident.span();
         match ident {
-            ReturnType::Default => {
-                return Err((span, "default return type does not support"));
-            }
+            ReturnType::Default => Err((span, "default return type does not support")),
             ReturnType::Type(_, inner) => match self {
                 Action::Fetch => Ok((quote! {#inner}, quote! { #inner })),
                 Action::Exists => Ok((quote! {::conservator::ExistsRow}, quote! { bool })),
@@ -164,6 +182,9 @@ impl Action {
                     Ok((quote! {#inner_type}, quote! { #inner }))
                 }
                 Action::Execute => Ok((quote! { ::conservator::SingleNumberRow }, quote! { () })),
+                Action::ExecuteCount => {
+                    Ok((quote! { ::conservator::SingleNumberRow }, quote! { u64 }))
+                }
             },
         }
     }
@@ -179,7 +200,7 @@ pub(crate) fn handler(
         Err(_) => return Err((args.span(), "unknown action type")),
     };
 
-    let input_span = input.span().clone();
+    let input_span = input.span();
     let method = match parse2::<ItemFn>(input) {
         Ok(func) => func,
         Err(_) => return Err((input_span, "unknown action type")),
@@ -191,13 +212,12 @@ pub(crate) fn handler(
 
     let output = &method.sig.output;
 
-    let (fetch_model, return_type) = action.extract_and_build_ret_type(&output)?;
+    let (fetch_model, return_type) = action.extract_and_build_ret_type(output)?;
     let body = &method.block;
     let body: Vec<proc_macro2::TokenStream> = body
         .stmts
         .iter()
-        .cloned()
-        .map(|stmt| match &stmt {
+        .map(|stmt| match stmt {
             Stmt::Expr(Expr::Lit(expr_lit)) => match &expr_lit.lit {
                 Lit::Str(lit_str) => {
                     let mut sql = lit_str.value();
@@ -350,6 +370,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_return_affected_rows_for_execute_count() {
+        use quote::quote;
+        let args = quote! { execute_count };
+        let input = quote! {
+            pub async fn deactivate_user(id: i32) -> u64 {
+                "update users set active = false where id = :id"
+            }
+        };
+
+        let expected = quote! {
+            pub async fn deactivate_user<'e, 'c: 'e, E: 'e + ::sqlx::Executor<'c, Database = ::sqlx::Postgres>>(
+                id: i32,
+                executor: E
+            ) -> Result<u64, ::sqlx::Error> {
+                Ok(::sqlx::query_as!(::conservator::SingleNumberRow, "update users set active = false where id = $1", id,)
+                    .execute(executor)
+                    .await?.rows_affected())
+            }
+        };
+        assert_eq!(
+            expected.to_string(),
+            handler(args, input).unwrap().to_string()
+        );
+    }
+
     #[test]
     fn should_work_with_pg_double_mark() {
         use quote::quote;
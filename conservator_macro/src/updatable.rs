@@ -0,0 +1,87 @@
+use darling::{FromDeriveInput, FromField};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::{parse2, Data, DeriveInput};
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(updatable))]
+struct UpdatableOpts {
+    ident: syn::Ident,
+    #[darling(default, rename = "for")]
+    domain: Option<syn::Path>,
+}
+
+#[derive(Debug, FromField)]
+#[darling(attributes(updatable))]
+struct UpdatableFieldOpt {
+    ident: Option<syn::Ident>,
+}
+
+pub(crate) fn handle_updatable(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let x1 = parse2::<DeriveInput>(input).unwrap();
+    let updatable_opts: UpdatableOpts = UpdatableOpts::from_derive_input(&x1).unwrap();
+
+    let ident = updatable_opts.ident;
+
+    if let Data::Struct(ref body) = x1.data {
+        let fields = body
+            .fields
+            .iter()
+            .map(|it| UpdatableFieldOpt::from_field(it).unwrap())
+            .map(|it| it.ident.expect("ident not found"))
+            .collect::<Vec<_>>();
+        let columns = fields.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+
+        // only the `Some` fields are set, in declaration order, so columns and binds line up
+        let push_columns = fields.iter().zip(columns.iter()).map(|(field, column)| {
+            quote! {
+                if self. #field .is_some() {
+                    ret.push(#column);
+                }
+            }
+        });
+        let bind_list_for_query = fields.iter().map(|field| {
+            quote! {
+                if let Some(value) = self. #field {
+                    e = e.bind(value);
+                }
+            }
+        });
+
+        // same check as `#[creatable(for)]`, the domain's fields must be visible from this module
+        let domain_check = updatable_opts.domain.map(|domain| {
+            quote! {
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn check_columns_exist(it: #domain) {
+                        #(let _ = &it. #fields;)*
+                    }
+                };
+            }
+        });
+
+        quote! {
+            #domain_check
+
+            impl ::conservator::Updatable for #ident {
+                fn get_set_columns(&self) -> Vec<&'static str> {
+                    let mut ret = Vec::new();
+                    #(#push_columns)*
+                    ret
+                }
+                fn build_for_query<'q>(
+                    self,
+                    e: ::sqlx::query::Query<'q, ::sqlx::Postgres, <::sqlx::Postgres as ::sqlx::database::HasArguments<'q>>::Arguments>,
+                ) -> ::sqlx::query::Query<'q, ::sqlx::Postgres, <::sqlx::Postgres as ::sqlx::database::HasArguments<'q>>::Arguments> {
+                    let mut e = e;
+                    #(#bind_list_for_query)*
+                    e
+                }
+            }
+        }
+    } else {
+        abort! { x1,
+            "enum does not support"
+        }
+    }
+}